@@ -1,8 +1,11 @@
 use proc_macro::TokenStream;
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, ToTokens};
 use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, Ident};
 
-#[proc_macro_derive(EventBridge, attributes(forward_to_trait, trait_returned_type))]
+#[proc_macro_derive(
+    EventBridge,
+    attributes(forward_to_trait, trait_returned_type, forward)
+)]
 pub fn derive_generate_forward_to(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let enum_name = input.ident;
@@ -20,19 +23,47 @@ pub fn derive_generate_forward_to(input: TokenStream) -> TokenStream {
         Err(err) => return err.to_compile_error().into(),
     };
 
+    let enum_forward = match get_enum_forward(&input.attrs) {
+        Ok(enum_forward) => enum_forward,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let is_sync = enum_forward.sync;
+
     // Try to get the trait return type. If not found, fallback to `()`.
-    let trait_return_type_tokens = match get_trait_return_type(&input.attrs) {
-        Ok(Some(trt)) => quote!(#trt),
-        Ok(None) => quote!(()),
+    let trait_return_type = match get_trait_return_type(&input.attrs) {
+        Ok(trt) => trt,
         Err(err) => return err.to_compile_error().into(),
     };
+    let trait_return_type_tokens = match &trait_return_type {
+        Some(trt) => quote!(#trt),
+        None => quote!(()),
+    };
+
+    // Each variant forwards to its own #[forward_to_trait(...)] override if
+    // present, otherwise to the enum-level target. Collect the distinct set
+    // of traits referenced so the generated bound requires all of them.
+    let mut distinct_traits: Vec<syn::Path> = vec![trait_name.clone()];
+    for variant in &data_enum.variants {
+        let variant_trait = match get_variant_trait_override(&variant.attrs) {
+            Ok(Some(variant_trait)) => variant_trait,
+            Ok(None) => continue,
+            Err(err) => return err.to_compile_error().into(),
+        };
+        if !distinct_traits
+            .iter()
+            .any(|t| t.to_token_stream().to_string() == variant_trait.to_token_stream().to_string())
+        {
+            distinct_traits.push(variant_trait);
+        }
+    }
+    let trait_bound_tokens = quote! { #(#distinct_traits)+* };
 
-    let match_arms = data_enum.variants.iter().map(|variant| {
+    let mut match_arms = Vec::with_capacity(data_enum.variants.len());
+    let mut ref_match_arms = Vec::with_capacity(data_enum.variants.len());
+    for variant in &data_enum.variants {
         let variant_name = &variant.ident;
-        let snake_name = to_snake_case(&variant_name.to_string());
-        let method_ident = format_ident!("{}", snake_name);
 
-        let (pattern, args) = match &variant.fields {
+        let (pattern, args, cloned_args) = match &variant.fields {
             Fields::Named(fields) => {
                 let field_idents: Vec<Ident> = fields
                     .named
@@ -41,7 +72,8 @@ pub fn derive_generate_forward_to(input: TokenStream) -> TokenStream {
                     .collect();
                 let pattern = quote! { #enum_name::#variant_name { #( #field_idents ),* } };
                 let args = quote! { #( #field_idents ),* };
-                (pattern, args)
+                let cloned_args = quote! { #( #field_idents.clone() ),* };
+                (pattern, args, cloned_args)
             }
             Fields::Unnamed(fields) => {
                 let field_idents: Vec<Ident> = (0..fields.unnamed.len())
@@ -49,23 +81,95 @@ pub fn derive_generate_forward_to(input: TokenStream) -> TokenStream {
                     .collect();
                 let pattern = quote! { #enum_name::#variant_name(#( #field_idents ),*) };
                 let args = quote! { #( #field_idents ),* };
-                (pattern, args)
+                let cloned_args = quote! { #( #field_idents.clone() ),* };
+                (pattern, args, cloned_args)
             }
             Fields::Unit => {
                 let pattern = quote! { #enum_name::#variant_name };
                 let args = quote! {};
-                (pattern, args)
+                let cloned_args = quote! {};
+                (pattern, args, cloned_args)
             }
         };
 
-        quote! {
-            #pattern => api.#method_ident(#args).await,
+        let forward = match get_variant_forward(&variant.attrs) {
+            Ok(forward) => forward,
+            Err(err) => return err.to_compile_error().into(),
+        };
+
+        if forward.skip {
+            // With no `#[trait_returned_type(...)]` the return type is `()`,
+            // so skipping a variant is a silent no-op, its most natural use.
+            // With a returning trait there is no value to manufacture, so
+            // reject it at compile time instead of deferring to a runtime
+            // panic the caller could otherwise trigger just by constructing
+            // and forwarding the variant.
+            let arm = if trait_return_type.is_none() {
+                quote! { #pattern => {}, }
+            } else {
+                let err = syn::Error::new_spanned(
+                    variant_name,
+                    "`#[forward(skip)]` cannot be used together with `#[trait_returned_type(...)]`: \
+                     there is no value to return for a skipped variant",
+                )
+                .to_compile_error();
+                quote! { #pattern => #err, }
+            };
+            match_arms.push(arm.clone());
+            ref_match_arms.push(arm);
+            continue;
         }
-    });
 
-    let expanded = quote! {
-        impl #enum_name {
-            pub async fn forward_to<T: #trait_name + ?Sized>(self, api: &mut T) -> #trait_return_type_tokens {
+        let method_ident = forward
+            .rename
+            .unwrap_or_else(|| format_ident!("{}", to_snake_case(&variant_name.to_string())));
+
+        // Dispatch through the variant's own resolved trait via fully-qualified
+        // syntax, rather than a plain `api.#method_ident(...)` method call: with
+        // a combined `T: TraitA + TraitB` bound, a same-named method on two
+        // routed traits would otherwise be an ambiguous call instead of routing
+        // to the trait the variant actually declared.
+        let variant_trait = match get_variant_trait_override(&variant.attrs) {
+            Ok(Some(variant_trait)) => variant_trait,
+            Ok(None) => trait_name.clone(),
+            Err(err) => return err.to_compile_error().into(),
+        };
+
+        let build_call = |args: &proc_macro2::TokenStream| {
+            let method_call = quote! { <T as #variant_trait>::#method_ident(api, #args) };
+            match (is_sync, enum_forward.map_err) {
+                (true, true) => quote! { #method_call.map_err(Into::into) },
+                (true, false) => quote! { #method_call },
+                (false, true) => quote! { #method_call.await.map_err(Into::into) },
+                (false, false) => quote! { #method_call.await },
+            }
+        };
+
+        let call = build_call(&args);
+        match_arms.push(quote! {
+            #pattern => #call,
+        });
+
+        // `forward_to_ref` matches on `&self`, so fields already bind by
+        // reference via match ergonomics; `.clone()` them into owned values
+        // for the (by-value) trait method parameters.
+        let ref_call = build_call(&cloned_args);
+        ref_match_arms.push(quote! {
+            #pattern => #ref_call,
+        });
+    }
+
+    let forward_to_fn = if is_sync {
+        quote! {
+            pub fn forward_to<T: #trait_bound_tokens + ?Sized>(self, api: &mut T) -> #trait_return_type_tokens {
+                match self {
+                    #( #match_arms )*
+                }
+            }
+        }
+    } else {
+        quote! {
+            pub async fn forward_to<T: #trait_bound_tokens + ?Sized>(self, api: &mut T) -> #trait_return_type_tokens {
                 match self {
                     #( #match_arms )*
                 }
@@ -73,34 +177,130 @@ pub fn derive_generate_forward_to(input: TokenStream) -> TokenStream {
         }
     };
 
+    let forward_to_ref_fn = if is_sync {
+        quote! {
+            pub fn forward_to_ref<T: #trait_bound_tokens + ?Sized>(&self, api: &mut T) -> #trait_return_type_tokens {
+                match self {
+                    #( #ref_match_arms )*
+                }
+            }
+        }
+    } else {
+        quote! {
+            pub async fn forward_to_ref<T: #trait_bound_tokens + ?Sized>(&self, api: &mut T) -> #trait_return_type_tokens {
+                match self {
+                    #( #ref_match_arms )*
+                }
+            }
+        }
+    };
+
+    let expanded = quote! {
+        impl #enum_name {
+            #forward_to_fn
+            #forward_to_ref_fn
+        }
+    };
+
     expanded.into()
 }
 
-/// Convert CamelCase to snake_case
+/// Convert CamelCase to snake_case, treating a run of consecutive uppercase
+/// letters as a single acronym rather than splitting every letter: `HTTPUrl`
+/// becomes `http_url`, not `h_t_t_p_url`.
 fn to_snake_case(input: &str) -> String {
+    let chars: Vec<char> = input.chars().collect();
     let mut s = String::new();
-    for (i, ch) in input.char_indices() {
+    for (i, &ch) in chars.iter().enumerate() {
         if ch.is_uppercase() && i != 0 {
-            s.push('_');
+            let prev = chars[i - 1];
+            let next = chars.get(i + 1).copied();
+            let at_word_start = prev.is_lowercase() || prev.is_ascii_digit();
+            let at_acronym_end = prev.is_uppercase() && next.is_some_and(|n| n.is_lowercase());
+            if at_word_start || at_acronym_end {
+                s.push('_');
+            }
         }
-        s.push(ch.to_ascii_lowercase());
+        s.extend(ch.to_lowercase());
     }
     s
 }
 
-/// Parse the trait name from the #[forward_to_trait(...)] attribute.
-fn get_trait_name(attrs: &[Attribute]) -> syn::Result<Ident> {
+/// Enum-level forwarding customization parsed from `#[forward(...)]`.
+#[derive(Default)]
+struct EnumForward {
+    /// Generates a plain synchronous `forward_to`, with no `async`/`.await`:
+    /// `#[forward(sync)]`.
+    sync: bool,
+    /// Widens the underlying trait methods' error type into
+    /// `trait_returned_type`'s error via `.map_err(Into::into)`:
+    /// `#[forward(map_err)]`.
+    map_err: bool,
+}
+
+/// Parse the `#[forward(sync)]` / `#[forward(map_err)]` attributes on the enum itself.
+fn get_enum_forward(attrs: &[Attribute]) -> syn::Result<EnumForward> {
+    let mut forward = EnumForward::default();
+    for attr in attrs {
+        if attr.path().is_ident("forward") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("sync") {
+                    forward.sync = true;
+                    Ok(())
+                } else if meta.path.is_ident("map_err") {
+                    forward.map_err = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported `forward` attribute, expected `sync` or `map_err`"))
+                }
+            })?;
+        }
+    }
+    Ok(forward)
+}
+
+/// Per-variant forwarding customization parsed from `#[forward(...)]`.
+#[derive(Default)]
+struct VariantForward {
+    /// Overrides the derived method name: `#[forward(rename = "custom_method")]`.
+    rename: Option<Ident>,
+    /// Omits the variant from forwarding: `#[forward(skip)]`. Its match arm
+    /// becomes a no-op when the enum has no `#[trait_returned_type(...)]`
+    /// (and so returns `()`); combining it with any
+    /// `#[trait_returned_type(...)]`, even `()`, is a compile error.
+    skip: bool,
+}
+
+/// Parse the `#[forward(rename = "...")]` / `#[forward(skip)]` attributes on a variant.
+fn get_variant_forward(attrs: &[Attribute]) -> syn::Result<VariantForward> {
+    let mut forward = VariantForward::default();
+    for attr in attrs {
+        if attr.path().is_ident("forward") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    forward.skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("rename") {
+                    let lit: syn::LitStr = meta.value()?.parse()?;
+                    forward.rename = Some(format_ident!("{}", lit.value()));
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported `forward` attribute, expected `rename` or `skip`"))
+                }
+            })?;
+        }
+    }
+    Ok(forward)
+}
+
+/// Parse the trait path from the #[forward_to_trait(...)] attribute.
+///
+/// Accepts full, possibly-qualified paths (e.g. `crate::api::TestApiTrait`) so
+/// that the target trait does not have to live in scope as a bare identifier.
+fn get_trait_name(attrs: &[Attribute]) -> syn::Result<syn::Path> {
     for attr in attrs {
         if attr.path().is_ident("forward_to_trait") {
-            let path: syn::Path = attr.parse_args()?;
-            if let Some(ident) = path.get_ident() {
-                return Ok(ident.clone());
-            } else {
-                return Err(syn::Error::new_spanned(
-                    path,
-                    "Trait path must be a single identifier",
-                ));
-            }
+            return attr.parse_args::<syn::Path>();
         }
     }
     Err(syn::Error::new(
@@ -109,20 +309,22 @@ fn get_trait_name(attrs: &[Attribute]) -> syn::Result<Ident> {
     ))
 }
 
+/// Parse a variant-level `#[forward_to_trait(...)]` override, if present.
+fn get_variant_trait_override(attrs: &[Attribute]) -> syn::Result<Option<syn::Path>> {
+    for attr in attrs {
+        if attr.path().is_ident("forward_to_trait") {
+            return attr.parse_args::<syn::Path>().map(Some);
+        }
+    }
+    Ok(None)
+}
+
 /// Parse the return type from the #[trait_returned_type(...)] attribute.
 /// If not found, return Ok(None) to indicate no type was provided.
-fn get_trait_return_type(attrs: &[Attribute]) -> syn::Result<Option<Ident>> {
+fn get_trait_return_type(attrs: &[Attribute]) -> syn::Result<Option<syn::Type>> {
     for attr in attrs {
         if attr.path().is_ident("trait_returned_type") {
-            let path: syn::Path = attr.parse_args()?;
-            if let Some(ident) = path.get_ident() {
-                return Ok(Some(ident.clone()));
-            } else {
-                return Err(syn::Error::new_spanned(
-                    path,
-                    "Return type must be a single identifier",
-                ));
-            }
+            return attr.parse_args::<syn::Type>().map(Some);
         }
     }
     // Not found, return None
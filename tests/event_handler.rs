@@ -228,3 +228,385 @@ async fn test_string_arg_no_return() {
         .forward_to(&mut mock)
         .await;
 }
+
+// Test module for qualified trait paths and full generic return types
+mod qualified_trait {
+    use super::*;
+
+    pub mod api {
+        use async_trait::async_trait;
+
+        #[async_trait]
+        pub trait TestApiTrait {
+            async fn set_index(&mut self, index: i32) -> Result<i32, String>;
+        }
+    }
+
+    #[derive(EventBridge)]
+    #[forward_to_trait(api::TestApiTrait)]
+    #[trait_returned_type(Result<i32, String>)]
+    pub enum TestEvent {
+        SetIndex(i32),
+    }
+
+    mock! {
+        pub TestApiImpl {}
+
+        #[async_trait]
+        impl api::TestApiTrait for TestApiImpl {
+            async fn set_index(&mut self, index: i32) -> Result<i32, String>;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_qualified_trait_and_full_return_type() {
+        let mut mock = MockTestApiImpl::new();
+        mock.expect_set_index()
+            .with(eq(42))
+            .once()
+            .returning(Ok);
+
+        let result = TestEvent::SetIndex(42).forward_to(&mut mock).await;
+        assert_eq!(result, Ok(42));
+    }
+}
+
+// Test module for acronym handling, renaming and skipping
+mod variant_attrs {
+    use super::*;
+
+    #[async_trait]
+    pub trait AcronymApiTrait {
+        async fn set_http_url(&mut self, url: String) -> Result<(), String>;
+        async fn renamed_target(&mut self, index: i32) -> Result<(), String>;
+        async fn not_skipped(&mut self) -> Result<(), String>;
+    }
+
+    mock! {
+        pub AcronymApiImpl {}
+
+        #[async_trait]
+        impl AcronymApiTrait for AcronymApiImpl {
+            async fn set_http_url(&mut self, url: String) -> Result<(), String>;
+            async fn renamed_target(&mut self, index: i32) -> Result<(), String>;
+            async fn not_skipped(&mut self) -> Result<(), String>;
+        }
+    }
+
+    #[derive(EventBridge)]
+    #[forward_to_trait(AcronymApiTrait)]
+    #[trait_returned_type(Result<(), String>)]
+    pub enum AcronymEvent {
+        SetHTTPUrl(String),
+        #[forward(rename = "renamed_target")]
+        CustomNamed(i32),
+        NotSkipped,
+    }
+
+    #[tokio::test]
+    async fn test_acronym_method_name() {
+        let mut mock = MockAcronymApiImpl::new();
+        mock.expect_set_http_url()
+            .with(eq("http://example.com".to_string()))
+            .once()
+            .returning(|_| Ok(()));
+
+        assert!(AcronymEvent::SetHTTPUrl("http://example.com".to_string())
+            .forward_to(&mut mock)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_variant_rename() {
+        let mut mock = MockAcronymApiImpl::new();
+        mock.expect_renamed_target()
+            .with(eq(7))
+            .once()
+            .returning(|_| Ok(()));
+
+        assert!(AcronymEvent::CustomNamed(7)
+            .forward_to(&mut mock)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_not_skipped() {
+        let mut mock = MockAcronymApiImpl::new();
+        mock.expect_not_skipped().once().returning(|| Ok(()));
+
+        assert!(AcronymEvent::NotSkipped.forward_to(&mut mock).await.is_ok());
+    }
+
+    // `#[forward(skip)]` is only usable without `#[trait_returned_type(...)]`,
+    // where `forward_to` returns `()` and a skipped variant is a silent no-op
+    // rather than a panic.
+    #[async_trait]
+    pub trait UnitReturnApiTrait {
+        async fn do_something(&mut self);
+    }
+
+    mock! {
+        pub UnitReturnApiImpl {}
+
+        #[async_trait]
+        impl UnitReturnApiTrait for UnitReturnApiImpl {
+            async fn do_something(&mut self);
+        }
+    }
+
+    #[derive(EventBridge)]
+    #[forward_to_trait(UnitReturnApiTrait)]
+    pub enum UnitReturnEvent {
+        DoSomething,
+        #[forward(skip)]
+        Skipped,
+    }
+
+    #[tokio::test]
+    async fn test_unit_return_forwarding() {
+        let mut mock = MockUnitReturnApiImpl::new();
+        mock.expect_do_something().once().returning(|| ());
+
+        UnitReturnEvent::DoSomething.forward_to(&mut mock).await;
+    }
+
+    #[tokio::test]
+    async fn test_variant_skip_is_noop_with_unit_return() {
+        // No expectations are set, so a call reaching the mock would panic -
+        // proving the skipped variant makes no call and just returns `()`.
+        let mut mock = MockUnitReturnApiImpl::new();
+
+        UnitReturnEvent::Skipped.forward_to(&mut mock).await;
+    }
+}
+
+// Test module for the synchronous (non-async) forwarding mode
+mod sync_forwarding {
+    use super::*;
+
+    #[mockall::automock]
+    pub trait SyncApiTrait {
+        fn set_index(&mut self, index: i32) -> Result<(), String>;
+    }
+
+    #[derive(EventBridge)]
+    #[forward_to_trait(SyncApiTrait)]
+    #[trait_returned_type(Result<(), String>)]
+    #[forward(sync)]
+    pub enum SyncEvent {
+        SetIndex(i32),
+    }
+
+    #[test]
+    fn test_sync_forwarding() {
+        let mut mock = MockSyncApiTrait::new();
+        mock.expect_set_index()
+            .with(eq(42))
+            .once()
+            .returning(|_| Ok(()));
+
+        assert!(SyncEvent::SetIndex(42).forward_to(&mut mock).is_ok());
+    }
+}
+
+// Test module for routing different variants to different traits
+mod multi_trait_routing {
+    use super::*;
+
+    #[async_trait]
+    pub trait ApiTrait {
+        async fn set_index(&mut self, index: i32) -> Result<(), String>;
+    }
+
+    #[async_trait]
+    pub trait StorageTrait {
+        async fn persist(&mut self) -> Result<(), String>;
+    }
+
+    #[derive(EventBridge)]
+    #[forward_to_trait(ApiTrait)]
+    #[trait_returned_type(Result<(), String>)]
+    pub enum RoutedEvent {
+        SetIndex(i32),
+        #[forward_to_trait(StorageTrait)]
+        Persist,
+    }
+
+    pub struct Handler {
+        pub index: Option<i32>,
+        pub persisted: bool,
+    }
+
+    #[async_trait]
+    impl ApiTrait for Handler {
+        async fn set_index(&mut self, index: i32) -> Result<(), String> {
+            self.index = Some(index);
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl StorageTrait for Handler {
+        async fn persist(&mut self) -> Result<(), String> {
+            self.persisted = true;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_routes_variants_to_their_own_trait() {
+        let mut handler = Handler {
+            index: None,
+            persisted: false,
+        };
+
+        assert!(RoutedEvent::SetIndex(7)
+            .forward_to(&mut handler)
+            .await
+            .is_ok());
+        assert_eq!(handler.index, Some(7));
+
+        assert!(RoutedEvent::Persist.forward_to(&mut handler).await.is_ok());
+        assert!(handler.persisted);
+    }
+
+    // Both traits below expose a same-named `reset` method. Routing must
+    // dispatch via the variant's own declared trait rather than a plain
+    // `api.reset(...)` call, which would be ambiguous under the combined
+    // `T: ResettableApiTrait + ResettableStorageTrait` bound.
+    #[async_trait]
+    pub trait ResettableApiTrait {
+        async fn reset(&mut self) -> Result<(), String>;
+    }
+
+    #[async_trait]
+    pub trait ResettableStorageTrait {
+        async fn reset(&mut self) -> Result<(), String>;
+    }
+
+    #[derive(EventBridge)]
+    #[forward_to_trait(ResettableApiTrait)]
+    #[trait_returned_type(Result<(), String>)]
+    pub enum ResetEvent {
+        #[forward(rename = "reset")]
+        ResetApi,
+        #[forward(rename = "reset")]
+        #[forward_to_trait(ResettableStorageTrait)]
+        ResetStorage,
+    }
+
+    pub struct ResettableHandler {
+        pub api_reset: bool,
+        pub storage_reset: bool,
+    }
+
+    #[async_trait]
+    impl ResettableApiTrait for ResettableHandler {
+        async fn reset(&mut self) -> Result<(), String> {
+            self.api_reset = true;
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl ResettableStorageTrait for ResettableHandler {
+        async fn reset(&mut self) -> Result<(), String> {
+            self.storage_reset = true;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_routes_same_named_method_to_its_own_trait() {
+        let mut handler = ResettableHandler {
+            api_reset: false,
+            storage_reset: false,
+        };
+
+        assert!(ResetEvent::ResetApi.forward_to(&mut handler).await.is_ok());
+        assert!(handler.api_reset);
+        assert!(!handler.storage_reset);
+
+        assert!(ResetEvent::ResetStorage
+            .forward_to(&mut handler)
+            .await
+            .is_ok());
+        assert!(handler.storage_reset);
+    }
+}
+
+// Test module for widening narrower subsystem errors into one enum via `Into`
+mod error_widening {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    pub struct AppError(String);
+
+    impl From<String> for AppError {
+        fn from(err: String) -> Self {
+            AppError(err)
+        }
+    }
+
+    #[async_trait]
+    pub trait NarrowErrorApiTrait {
+        async fn set_index(&mut self, index: i32) -> Result<(), String>;
+    }
+
+    mock! {
+        pub NarrowErrorApiImpl {}
+
+        #[async_trait]
+        impl NarrowErrorApiTrait for NarrowErrorApiImpl {
+            async fn set_index(&mut self, index: i32) -> Result<(), String>;
+        }
+    }
+
+    #[derive(EventBridge)]
+    #[forward_to_trait(NarrowErrorApiTrait)]
+    #[trait_returned_type(Result<(), AppError>)]
+    #[forward(map_err)]
+    pub enum WideningEvent {
+        SetIndex(i32),
+    }
+
+    #[tokio::test]
+    async fn test_widens_error_via_into() {
+        let mut mock = MockNarrowErrorApiImpl::new();
+        mock.expect_set_index()
+            .with(eq(42))
+            .once()
+            .returning(|_| Err("boom".to_string()));
+
+        let result = WideningEvent::SetIndex(42).forward_to(&mut mock).await;
+        assert_eq!(result, Err(AppError("boom".to_string())));
+    }
+}
+
+// Test module for reference-based forwarding that does not consume the event
+mod ref_forwarding {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_forward_to_ref_does_not_consume_event() {
+        let mut primary = MockTestApiImpl::new();
+        primary
+            .expect_set_name()
+            .with(eq("test".to_string()))
+            .once()
+            .returning(|_| Ok(()));
+
+        let mut audit = MockTestApiImpl::new();
+        audit
+            .expect_set_name()
+            .with(eq("test".to_string()))
+            .once()
+            .returning(|_| Ok(()));
+
+        let event = TestEvent::SetName("test".to_string());
+        assert!(event.forward_to_ref(&mut primary).await.is_ok());
+        assert!(event.forward_to_ref(&mut audit).await.is_ok());
+    }
+}